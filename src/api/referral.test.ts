@@ -40,7 +40,8 @@ vi.mock('../services/referral/rewardService.ts', () => ({
             maxReferrals: 10,
             minActionValue: 10,
             cooldownPeriod: 24 * 60 * 60 * 1000,
-            maxReferralsPerCooldownPeriod: 5
+            maxReferralsPerCooldownPeriod: 5,
+            secondLevelRewardPercentage: 10
         };
 
         constructor() {}
@@ -196,7 +197,8 @@ vi.mock('../services/referral/index.ts', () => ({
                 maxReferrals: 10,
                 minActionValue: 10,
                 cooldownPeriod: 24 * 60 * 60 * 1000,
-                maxReferralsPerCooldownPeriod: 5
+                maxReferralsPerCooldownPeriod: 5,
+                secondLevelRewardPercentage: 10
             };
         }
         async updateRewardConfig(newConfig: any) {
@@ -206,7 +208,8 @@ vi.mock('../services/referral/index.ts', () => ({
                 maxReferrals: newConfig.maxReferrals || 10,
                 minActionValue: newConfig.minActionValue || 10,
                 cooldownPeriod: newConfig.cooldownPeriod || 24 * 60 * 60 * 1000,
-                maxReferralsPerCooldownPeriod: newConfig.maxReferralsPerCooldownPeriod || 5
+                maxReferralsPerCooldownPeriod: newConfig.maxReferralsPerCooldownPeriod || 5,
+                secondLevelRewardPercentage: newConfig.secondLevelRewardPercentage || 10
             };
         }
         async processReward() {
@@ -326,7 +329,8 @@ vi.mock('../services/referral/index.ts', () => ({
             maxReferrals: 10,
             minActionValue: 10,
             cooldownPeriod: 24 * 60 * 60 * 1000,
-            maxReferralsPerCooldownPeriod: 5
+            maxReferralsPerCooldownPeriod: 5,
+            secondLevelRewardPercentage: 10
         }),
         updateRewardConfig: async (newConfig: any) => ({
             baseReward: newConfig.baseReward || 100,
@@ -334,7 +338,8 @@ vi.mock('../services/referral/index.ts', () => ({
             maxReferrals: newConfig.maxReferrals || 10,
             minActionValue: newConfig.minActionValue || 10,
             cooldownPeriod: newConfig.cooldownPeriod || 24 * 60 * 60 * 1000,
-            maxReferralsPerCooldownPeriod: newConfig.maxReferralsPerCooldownPeriod || 5
+            maxReferralsPerCooldownPeriod: newConfig.maxReferralsPerCooldownPeriod || 5,
+            secondLevelRewardPercentage: newConfig.secondLevelRewardPercentage || 10
         }),
         processReward: async () => ({
             referrerAddress: 'E8fgSKVQYf93xNrJhPWdQZi4Rz5fL4WDJLM727Pe2P97',
@@ -685,6 +690,7 @@ describe('Referral API', () => {
             expect(response.body.data.minActionValue).toBe(10);
             expect(response.body.data.cooldownPeriod).toBe(24 * 60 * 60 * 1000);
             expect(response.body.data.maxReferralsPerCooldownPeriod).toBe(5);
+            expect(response.body.data.secondLevelRewardPercentage).toBe(10);
         });
     });
 
@@ -727,6 +733,27 @@ describe('Referral API', () => {
 
             expect(response.body.error).toBe('Unauthorized');
         });
+
+        it('should fail with 400 for a second-level percentage above 100', async () => {
+            const response = await supertest(app)
+                .post('/api/v1/referral/rewards/config')
+                .set('x-admin-token', 'valid-admin-token')
+                .send({ secondLevelRewardPercentage: 150 })
+                .expect(400);
+
+            expect(response.body.error.message).toBe('Validation failed');
+            expect(response.body.error.details.fields.secondLevelRewardPercentage).toBe('Must be at most 100');
+        });
+
+        it('should fail with 400 for a negative second-level percentage', async () => {
+            const response = await supertest(app)
+                .post('/api/v1/referral/rewards/config')
+                .set('x-admin-token', 'valid-admin-token')
+                .send({ secondLevelRewardPercentage: -5 })
+                .expect(400);
+
+            expect(response.body.error.details.fields.secondLevelRewardPercentage).toBe('Must be at least 0');
+        });
     });
 
     describe('POST /api/v1/referral/rewards/process', () => {
@@ -11,6 +11,7 @@ import {
   validateCodeSchema,
   createReferralSchema,
   processRewardSchema,
+  rewardConfigSchema,
   extendExpirationSchema,
   regenerateCodeSchema,
   walletAddressParamSchema
@@ -488,6 +489,10 @@ router.post(
  *                           type: number
  *                           description: Total rewards earned from referrals
  *                           example: 450
+ *                         secondLevelRewards:
+ *                           type: number
+ *                           description: Rewards earned from referrals made by referred wallets
+ *                           example: 30
  *                         averageReward:
  *                           type: number
  *                           description: Average reward per referral
@@ -702,6 +707,10 @@ router.get(
  *                           type: number
  *                           description: Maximum referrals allowed during cooldown
  *                           example: 5
+ *                         secondLevelRewardPercentage:
+ *                           type: number
+ *                           description: Percentage of each reward credited to the referrer's own referrer
+ *                           example: 10
  *       429:
  *         description: Too many requests
  *       500:
@@ -720,7 +729,8 @@ router.get(
       maxReferrals: config.maxReferrals,
       minActionValue: config.minActionValue,
       cooldownPeriod: config.cooldownPeriod,
-              maxReferralsPerCooldownPeriod: config.maxReferralsPerCooldownPeriod
+              maxReferralsPerCooldownPeriod: config.maxReferralsPerCooldownPeriod,
+      secondLevelRewardPercentage: config.secondLevelRewardPercentage
     }));
   })
 );
@@ -759,6 +769,10 @@ router.get(
  *                           type: number
  *                           description: Total rewards earned
  *                           example: 450
+ *                         secondLevelRewards:
+ *                           type: number
+ *                           description: Rewards earned from referrals made by referred wallets
+ *                           example: 30
  *                         totalReferrals:
  *                           type: number
  *                           description: Total number of referrals
@@ -831,6 +845,12 @@ router.get(
  *                 type: number
  *                 description: Maximum referrals allowed during cooldown
  *                 example: 5
+ *               secondLevelRewardPercentage:
+ *                 type: number
+ *                 minimum: 0
+ *                 maximum: 100
+ *                 description: Percentage of each reward credited to the referrer's own referrer
+ *                 example: 10
  *     responses:
  *       200:
  *         description: Reward configuration updated successfully
@@ -850,6 +870,12 @@ router.get(
  *                         config:
  *                           type: object
  *                           description: Updated configuration
+ *       400:
+ *         description: Validation error - Invalid configuration values
+ *         content:
+ *           application/json:
+ *             schema:
+ *               $ref: '#/components/schemas/Error'
  *       401:
  *         description: Unauthorized - Admin authentication required
  *         content:
@@ -866,8 +892,9 @@ router.post(
   '/rewards/config',
   adminRateLimiter, // Admin operation
   requireAdminAuth,
+  validateBody(rewardConfigSchema),
   errorHandlerAsync(async (req: Request, res: Response) => {
-    const { baseReward, bonusMultiplier, maxReferrals, minActionValue, cooldownPeriod, maxReferralsPerCooldownPeriod } = req.body;
+    const { baseReward, bonusMultiplier, maxReferrals, minActionValue, cooldownPeriod, maxReferralsPerCooldownPeriod, secondLevelRewardPercentage } = req.body;
 
     const updatedConfig = await referralService.updateRewardConfig({
       baseReward,
@@ -875,7 +902,8 @@ router.post(
       maxReferrals,
       minActionValue,
       cooldownPeriod,
-              maxReferralsPerCooldownPeriod
+              maxReferralsPerCooldownPeriod,
+      secondLevelRewardPercentage
     });
 
     return res.status(200).json(successResponse({
@@ -919,6 +947,10 @@ router.post(
  *                           type: number
  *                           description: Total rewards earned
  *                           example: 450
+ *                         secondLevelRewards:
+ *                           type: number
+ *                           description: Rewards earned from referrals made by referred wallets
+ *                           example: 30
  *                         totalReferrals:
  *                           type: number
  *                           description: Total number of referrals
@@ -72,6 +72,40 @@ export const processRewardSchema: ValidationSchema = {
   }
 };
 
+/**
+ * Schema for updating reward configuration
+ */
+export const rewardConfigSchema: ValidationSchema = {
+  baseReward: {
+    required: false,
+    rules: [ValidationRules.isNumber(), ValidationRules.min(0)]
+  },
+  bonusMultiplier: {
+    required: false,
+    rules: [ValidationRules.isNumber(), ValidationRules.min(0)]
+  },
+  maxReferrals: {
+    required: false,
+    rules: [ValidationRules.isNumber(), ValidationRules.min(0)]
+  },
+  minActionValue: {
+    required: false,
+    rules: [ValidationRules.isNumber(), ValidationRules.min(0)]
+  },
+  cooldownPeriod: {
+    required: false,
+    rules: [ValidationRules.isNumber(), ValidationRules.min(0)]
+  },
+  maxReferralsPerCooldownPeriod: {
+    required: false,
+    rules: [ValidationRules.isNumber(), ValidationRules.min(0)]
+  },
+  secondLevelRewardPercentage: {
+    required: false,
+    rules: [ValidationRules.isNumber(), ValidationRules.min(0), ValidationRules.max(100)]
+  }
+};
+
 /**
  * Schema for extending expiration
  */
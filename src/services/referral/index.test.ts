@@ -45,7 +45,8 @@ vi.mock('./rewardService.ts', () => ({
                 maxReferrals: 10,
                 minActionValue: 10,
                 cooldownPeriod: 24 * 60 * 60 * 1000,
-                maxReferralsPerCooldownPeriod: 5
+                maxReferralsPerCooldownPeriod: 5,
+                secondLevelRewardPercentage: 10
             };
         }
         updateRewardConfig() { }
@@ -305,6 +306,7 @@ describe('ReferralService', () => {
 
             expect(stats.totalReferrals).toBe(0); // Will be 1 after referral is confirmed
             expect(stats.totalRewards).toBe(0);
+            expect(stats.secondLevelRewards).toBe(0);
             expect(stats.referralCode).toBe('TEST123');
             expect(stats.referrals).toBeInstanceOf(Array);
         });
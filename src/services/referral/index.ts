@@ -347,6 +347,7 @@ export class ReferralService {
   async getReferralStats(walletAddress: string): Promise<{
     totalReferrals: number;
     totalRewards: number;
+    secondLevelRewards: number;
     referralCode: string;
     referrals: IReferral[];
   }> {
@@ -359,6 +360,7 @@ export class ReferralService {
     return {
       totalReferrals: referralCode?.totalReferrals || 0,
       totalRewards: referralCode?.totalRewards || 0,
+      secondLevelRewards: referralCode?.secondLevelRewards || 0,
       referralCode: referralCode?.referralCode || '',
       referrals
     };
@@ -398,7 +400,8 @@ export class ReferralService {
       maxReferrals: config.maxReferrals,
       minActionValue: config.minActionValue,
       cooldownPeriod: config.cooldownPeriod,
-      maxReferralsPerCooldownPeriod: config.maxReferralsPerCooldownPeriod
+      maxReferralsPerCooldownPeriod: config.maxReferralsPerCooldownPeriod,
+      secondLevelRewardPercentage: config.secondLevelRewardPercentage
     };
   }
 
@@ -414,7 +417,8 @@ export class ReferralService {
       maxReferrals: updatedConfig.maxReferrals,
       minActionValue: updatedConfig.minActionValue,
       cooldownPeriod: updatedConfig.cooldownPeriod,
-      maxReferralsPerCooldownPeriod: updatedConfig.maxReferralsPerCooldownPeriod
+      maxReferralsPerCooldownPeriod: updatedConfig.maxReferralsPerCooldownPeriod,
+      secondLevelRewardPercentage: updatedConfig.secondLevelRewardPercentage
     };
   }
 
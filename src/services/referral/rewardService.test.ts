@@ -6,13 +6,15 @@ import { ReferralModel, IReferral } from '../../models/Referral.ts';
 import { ReferralCodeModel, IReferralCode } from '../../models/ReferralCode.ts';
 import { connectToDatabase } from '../database.ts';
 
+const { mockDistributeReward } = vi.hoisted(() => ({
+    mockDistributeReward: vi.fn(async () => ({ txHash: 'mock-reward-tx', slot: 12346 }))
+}));
+
 // Mock external services
 vi.mock('../blockchain/referralProgram.ts', () => ({
     ReferralProgramService: class MockReferralProgramService {
         constructor() {}
-        async distributeReward() {
-            return { txHash: 'mock-reward-tx', slot: 12346 };
-        }
+        distributeReward = mockDistributeReward;
     }
 }));
 
@@ -135,6 +137,126 @@ describe('RewardService', () => {
 
             expect(rewardEvent).toBeNull();
         });
+
+        // MongoMemoryServer runs without a replica set, so these exercise processRewardWithoutTransaction;
+        // the transactional path shares creditSecondLevelReferrer but is not covered here.
+        describe('second-level rewards', () => {
+            const createUpstream = async (upstreamAddress: string, withReferralCode = true) => {
+                if (withReferralCode) {
+                    await ReferralCodeModel.create({
+                        walletAddress: upstreamAddress,
+                        referralCode: 'UPSTREAM123',
+                        isActive: true,
+                        totalReferrals: 1,
+                        totalRewards: 0
+                    });
+                }
+
+                await ReferralModel.create({
+                    referrerAddress: upstreamAddress,
+                    referreeAddress: 'referrer123',
+                    referralCode: 'UPSTREAM123',
+                    referralLink: 'https://clones-ai.com/ref/UPSTREAM123',
+                    firstActionType: 'wallet_connect',
+                    status: 'confirmed'
+                });
+            };
+
+            it('should credit the second-level referrer when the referrer was referred', async () => {
+                await createUpstream('upstream123');
+
+                const rewardEvent = await rewardService.processReward(
+                    'referrer123',
+                    'new-referree',
+                    'wallet_connect',
+                    100
+                );
+
+                expect(rewardEvent?.secondLevelReferrerAddress).toBe('upstream123');
+                expect(rewardEvent?.secondLevelRewardAmount).toBe(10);
+
+                const upstreamCode = await ReferralCodeModel.findOne({ walletAddress: 'upstream123' });
+                expect(upstreamCode?.secondLevelRewards).toBe(10);
+                expect(upstreamCode?.totalRewards).toBe(0);
+
+                expect(mockDistributeReward).toHaveBeenCalledTimes(2);
+                expect(mockDistributeReward).toHaveBeenCalledWith('referrer123', rewardEvent?.rewardAmount);
+                expect(mockDistributeReward).toHaveBeenCalledWith('upstream123', 10);
+            });
+
+            it('should not set second-level fields when the referrer has no referrer', async () => {
+                const rewardEvent = await rewardService.processReward(
+                    'referrer123',
+                    'new-referree',
+                    'wallet_connect',
+                    100
+                );
+
+                expect(rewardEvent).not.toBeNull();
+                expect(rewardEvent?.secondLevelReferrerAddress).toBeUndefined();
+                expect(rewardEvent?.secondLevelRewardAmount).toBeUndefined();
+                expect(mockDistributeReward).toHaveBeenCalledTimes(1);
+            });
+
+            it('should not credit or pay the second level when the percentage is 0', async () => {
+                await createUpstream('upstream123');
+                rewardService.updateRewardConfig({ secondLevelRewardPercentage: 0 });
+
+                try {
+                    const rewardEvent = await rewardService.processReward(
+                        'referrer123',
+                        'new-referree',
+                        'wallet_connect',
+                        100
+                    );
+
+                    expect(rewardEvent).not.toBeNull();
+                    expect(rewardEvent?.secondLevelReferrerAddress).toBeUndefined();
+
+                    const upstreamCode = await ReferralCodeModel.findOne({ walletAddress: 'upstream123' });
+                    expect(upstreamCode?.secondLevelRewards).toBe(0);
+                    expect(mockDistributeReward).toHaveBeenCalledTimes(1);
+                    expect(mockDistributeReward).toHaveBeenCalledWith('referrer123', rewardEvent?.rewardAmount);
+                } finally {
+                    rewardService.updateRewardConfig({ secondLevelRewardPercentage: 10 });
+                }
+            });
+
+            it('should not credit the referree through a referral loop', async () => {
+                // loop-wallet referred referrer123, and referrer123 now refers loop-wallet back
+                await createUpstream('loop-wallet');
+
+                const rewardEvent = await rewardService.processReward(
+                    'referrer123',
+                    'loop-wallet',
+                    'wallet_connect',
+                    100
+                );
+
+                expect(rewardEvent).not.toBeNull();
+                expect(rewardEvent?.secondLevelReferrerAddress).toBeUndefined();
+
+                const loopCode = await ReferralCodeModel.findOne({ walletAddress: 'loop-wallet' });
+                expect(loopCode?.secondLevelRewards).toBe(0);
+                expect(mockDistributeReward).toHaveBeenCalledTimes(1);
+            });
+
+            it('should not pay the second level when the upstream wallet has no referral code', async () => {
+                await createUpstream('upstream123', false);
+
+                const rewardEvent = await rewardService.processReward(
+                    'referrer123',
+                    'new-referree',
+                    'wallet_connect',
+                    100
+                );
+
+                expect(rewardEvent).not.toBeNull();
+                expect(rewardEvent?.secondLevelReferrerAddress).toBeUndefined();
+                expect(rewardEvent?.secondLevelRewardAmount).toBeUndefined();
+                expect(mockDistributeReward).toHaveBeenCalledTimes(1);
+            });
+        });
     });
 
     describe('getRewardStats', () => {
@@ -163,10 +285,24 @@ describe('RewardService', () => {
             expect(stats.recentRewards).toBeInstanceOf(Array);
         });
 
+        it('should report second-level rewards separately from direct rewards', async () => {
+            await ReferralCodeModel.findByIdAndUpdate(testReferralCode._id, {
+                totalRewards: 100,
+                secondLevelRewards: 30
+            });
+
+            const stats = await rewardService.getRewardStats('referrer123');
+
+            expect(stats.totalRewards).toBe(100);
+            expect(stats.secondLevelRewards).toBe(30);
+            expect(stats.averageReward).toBe(100); // Only direct rewards count towards the average
+        });
+
         it('should return zero stats for wallet without referrals', async () => {
             const stats = await rewardService.getRewardStats('no-referrals-wallet');
 
             expect(stats.totalRewards).toBe(0);
+            expect(stats.secondLevelRewards).toBe(0);
             expect(stats.totalReferrals).toBe(0);
             expect(stats.averageReward).toBe(0);
             expect(stats.recentRewards).toEqual([]);
@@ -183,6 +319,7 @@ describe('RewardService', () => {
             expect(config.minActionValue).toBe(10);
             expect(config.cooldownPeriod).toBe(24 * 60 * 60 * 1000);
             expect(config.maxReferralsPerCooldownPeriod).toBe(5);
+            expect(config.secondLevelRewardPercentage).toBe(10);
         });
     });
 
@@ -203,6 +340,16 @@ describe('RewardService', () => {
             expect(updatedConfig.maxReferrals).toBe(10); // Should remain unchanged
         });
 
+        it('should ignore keys that are explicitly undefined', async () => {
+            const originalConfig = rewardService.getRewardConfig();
+
+            rewardService.updateRewardConfig({ baseReward: 250, secondLevelRewardPercentage: undefined });
+
+            const updatedConfig = rewardService.getRewardConfig();
+            expect(updatedConfig.baseReward).toBe(250);
+            expect(updatedConfig.secondLevelRewardPercentage).toBe(originalConfig.secondLevelRewardPercentage);
+        });
+
         it('should partially update configuration', async () => {
             const originalConfig = rewardService.getRewardConfig();
             
@@ -11,6 +11,7 @@ export interface RewardConfig {
   minActionValue: number; // Minimum value of first action to qualify
   cooldownPeriod: number; // Cooldown period in milliseconds
   maxReferralsPerCooldownPeriod: number; // Maximum referrals allowed in cooldown period
+  secondLevelRewardPercentage: number; // Percentage of the reward credited to the referrer's own referrer
 }
 
 export interface RewardEvent {
@@ -19,6 +20,8 @@ export interface RewardEvent {
   actionType: string;
   actionValue: number;
   rewardAmount: number;
+  secondLevelReferrerAddress?: string;
+  secondLevelRewardAmount?: number;
   timestamp: Date;
 }
 
@@ -34,7 +37,8 @@ export class RewardService {
       maxReferrals: 10, // Max referrals for bonus
       minActionValue: 10, // Minimum 10 tokens worth of action
       cooldownPeriod: 24 * 60 * 60 * 1000, // 24 hours
-      maxReferralsPerCooldownPeriod: 5 // Maximum referrals per cooldown period
+      maxReferralsPerCooldownPeriod: 5, // Maximum referrals per cooldown period
+      secondLevelRewardPercentage: 10 // 10% of the reward goes to the referrer's referrer
     };
   }
 
@@ -57,6 +61,13 @@ export class RewardService {
     return Math.floor(reward);
   }
 
+  /**
+   * Calculate the share of a reward credited to the second-level referrer
+   */
+  private calculateSecondLevelReward(rewardAmount: number): number {
+    return Math.floor((rewardAmount * this.rewardConfig.secondLevelRewardPercentage) / 100);
+  }
+
   /**
    * Check if user is eligible for rewards (anti-abuse)
    */
@@ -143,20 +154,15 @@ export class RewardService {
             { session }
           );
 
+          // Credit the referrer's own referrer, if any (atomic within transaction)
+          await this.creditSecondLevelReferrer(rewardEvent, session);
+
           return rewardEvent;
         });
 
         // If we have a reward event, distribute it on-chain (outside transaction for reliability)
         if (result) {
-          try {
-            await this.referralProgramService.distributeReward(
-              referrerAddress,
-              result.rewardAmount
-            );
-          } catch (error) {
-            console.error('Failed to distribute reward on-chain:', error);
-            // Continue with off-chain reward tracking
-          }
+          await this.distributeRewards(result);
         }
 
         return result;
@@ -232,16 +238,11 @@ export class RewardService {
         { $inc: { totalRewards: rewardAmount } }
       );
 
-      // Distribute reward on-chain
-      try {
-        await this.referralProgramService.distributeReward(
-          referrerAddress,
-          rewardAmount
-        );
-      } catch (error) {
-        console.error('Failed to distribute reward on-chain:', error);
-        // Continue with off-chain reward tracking
-      }
+      // Credit the referrer's own referrer, if any
+      await this.creditSecondLevelReferrer(rewardEvent);
+
+      // Distribute rewards on-chain
+      await this.distributeRewards(rewardEvent);
 
       return rewardEvent;
 
@@ -251,11 +252,67 @@ export class RewardService {
     }
   }
 
+  /**
+   * Credit the referrer's own referrer with the second-level share of a reward event.
+   * Skips referral loops (the upstream wallet is the referree itself) and upstream
+   * wallets without a referral code.
+   */
+  private async creditSecondLevelReferrer(
+    rewardEvent: RewardEvent,
+    session?: mongoose.ClientSession
+  ): Promise<void> {
+    const secondLevelRewardAmount = this.calculateSecondLevelReward(rewardEvent.rewardAmount);
+    if (secondLevelRewardAmount <= 0) {
+      return;
+    }
+
+    const upstreamReferral = await ReferralModel.findOne(
+      { referreeAddress: rewardEvent.referrerAddress, status: 'confirmed' },
+      null,
+      { session }
+    );
+    if (!upstreamReferral || upstreamReferral.referrerAddress === rewardEvent.referreeAddress) {
+      return;
+    }
+
+    const upstreamCode = await ReferralCodeModel.findOneAndUpdate(
+      { walletAddress: upstreamReferral.referrerAddress },
+      { $inc: { secondLevelRewards: secondLevelRewardAmount } },
+      { session }
+    );
+    if (!upstreamCode) {
+      return; // No referral code to accrue to, so nothing is paid out
+    }
+
+    rewardEvent.secondLevelReferrerAddress = upstreamReferral.referrerAddress;
+    rewardEvent.secondLevelRewardAmount = secondLevelRewardAmount;
+  }
+
+  /**
+   * Distribute a reward event on-chain to the referrer and, if present, the second-level referrer
+   */
+  private async distributeRewards(rewardEvent: RewardEvent): Promise<void> {
+    const payouts: Array<[string, number]> = [[rewardEvent.referrerAddress, rewardEvent.rewardAmount]];
+    if (rewardEvent.secondLevelReferrerAddress && rewardEvent.secondLevelRewardAmount) {
+      payouts.push([rewardEvent.secondLevelReferrerAddress, rewardEvent.secondLevelRewardAmount]);
+    }
+
+    for (const [walletAddress, amount] of payouts) {
+      try {
+        await this.referralProgramService.distributeReward(walletAddress, amount);
+      } catch (error) {
+        console.error('Failed to distribute reward on-chain:', error);
+        // Continue with off-chain reward tracking
+      }
+    }
+  }
+
   /**
    * Get reward statistics for a wallet
    */
   async getRewardStats(walletAddress: string): Promise<{
     totalRewards: number;
+    secondLevelRewards: number;
     totalReferrals: number;
     averageReward: number;
     recentRewards: RewardEvent[];
@@ -267,11 +324,13 @@ export class RewardService {
     });
 
     const totalRewards = referralCode?.totalRewards || 0;
+    const secondLevelRewards = referralCode?.secondLevelRewards || 0;
     const totalReferrals = referrals.length;
     const averageReward = totalReferrals > 0 ? totalRewards / totalReferrals : 0;
 
     return {
       totalRewards,
+      secondLevelRewards,
       totalReferrals,
       averageReward,
       recentRewards: [] // Would need to track reward events separately
@@ -282,7 +341,11 @@ export class RewardService {
    * Update reward configuration
    */
   updateRewardConfig(newConfig: Partial<RewardConfig>): void {
-    this.rewardConfig = { ...this.rewardConfig, ...newConfig };
+    // Only merge keys that were actually provided so omitted fields keep their current value
+    const definedConfig = Object.fromEntries(
+      Object.entries(newConfig).filter(([, value]) => value !== undefined)
+    ) as Partial<RewardConfig>;
+    this.rewardConfig = { ...this.rewardConfig, ...definedConfig };
   }
 
   /**